@@ -1,9 +1,28 @@
 use std::env;
 use std::path::PathBuf;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn git_sha() -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn build_date() -> String {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs().to_string())
+        .unwrap_or_else(|_| "unknown".to_string())
+}
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let out_dir = PathBuf::from(env::var("OUT_DIR")?);
-    for proto in ["admin", "systemd", "hwid"].into_iter() {
+    for proto in ["admin", "systemd", "hwid", "buildinfo"].into_iter() {
         let outpath = out_dir.join(format!("{proto}_descriptor.bin"));
         let inpath = format!("api/{proto}/{proto}.proto");
 
@@ -11,5 +30,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             .file_descriptor_set_path(out_dir.join(outpath))
             .compile(&[inpath], &[proto])?;
     }
+
+    println!("cargo:rustc-env=GIVC_GIT_SHA={}", git_sha());
+    println!("cargo:rustc-env=GIVC_BUILD_DATE={}", build_date());
+    println!(
+        "cargo:rustc-env=GIVC_BUILD_FLAVOUR={}",
+        env::var("PROFILE").unwrap_or_else(|_| "unknown".to_string())
+    );
+    println!("cargo:rerun-if-changed=.git/HEAD");
+
     Ok(())
 }
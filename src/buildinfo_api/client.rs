@@ -0,0 +1,30 @@
+use crate::auth::BearerAuthInterceptor;
+use crate::endpoint::EndpointConfig;
+use crate::pb::{self, *};
+use tonic::service::interceptor::InterceptedService;
+use tonic::transport::Channel;
+
+type Client = pb::buildinfo::build_info_service_client::BuildInfoServiceClient<
+    InterceptedService<Channel, BearerAuthInterceptor>,
+>;
+
+#[derive(Debug)]
+pub struct BuildInfoClient {
+    endpoint: EndpointConfig,
+}
+
+impl BuildInfoClient {
+    pub fn new(endpoint: EndpointConfig) -> Self {
+        Self { endpoint }
+    }
+
+    async fn connect(&self) -> anyhow::Result<Client> {
+        Ok(Client::new(self.endpoint.intercepted_channel().await?))
+    }
+
+    pub async fn get_build_info(&self) -> anyhow::Result<pb::buildinfo::BuildInfoResponse> {
+        let request = pb::buildinfo::BuildInfoRequest {};
+        let resp = self.connect().await?.get_build_info(request).await?;
+        Ok(resp.into_inner())
+    }
+}
@@ -0,0 +1,51 @@
+use crate::pb;
+use tonic::{Request, Response};
+
+pub use pb::buildinfo::{
+    build_info_service_server::{BuildInfoService, BuildInfoServiceServer as GeneratedServer},
+    BuildInfoRequest, BuildInfoResponse,
+};
+type RResult<T> = tonic::Result<Response<T>>;
+
+/// Reports the version, commit and build date baked in at compile time by
+/// `build.rs`, so the admin layer can collect node versions for fleet
+/// diagnostics and upgrade gating.
+#[derive(Debug, Default, Clone)]
+pub struct BuildInfoServiceServer;
+
+impl BuildInfoServiceServer {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Wraps this server with [`crate::auth::AuthValidator`] so the generated
+    /// `BuildInfoServiceServer` rejects requests without a valid bearer
+    /// credential with `Code::Unauthenticated` before they reach
+    /// `get_build_info`. Mount the result on the `Router` instead of the bare
+    /// `BuildInfoServiceServer`.
+    pub fn authenticated(
+        self,
+        credential: crate::auth::Credential,
+    ) -> tonic::service::interceptor::InterceptedService<
+        GeneratedServer<Self>,
+        crate::auth::AuthValidator,
+    > {
+        crate::authenticated(GeneratedServer::new(self), credential)
+    }
+}
+
+#[tonic::async_trait]
+impl BuildInfoService for BuildInfoServiceServer {
+    async fn get_build_info(
+        &self,
+        _request: Request<BuildInfoRequest>,
+    ) -> RResult<BuildInfoResponse> {
+        Ok(Response::new(BuildInfoResponse {
+            product_name: env!("CARGO_PKG_NAME").to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            commit: env!("GIVC_GIT_SHA").to_string(),
+            build_date: env!("GIVC_BUILD_DATE").to_string(),
+            build_flavour: env!("GIVC_BUILD_FLAVOUR").to_string(),
+        }))
+    }
+}
@@ -0,0 +1,52 @@
+//! Connection details shared by every givc gRPC client.
+
+use anyhow::Result;
+use tonic::service::interceptor::InterceptedService;
+use tonic::transport::{Channel, Endpoint};
+
+use crate::auth::{BearerAuthInterceptor, Credential};
+
+/// Address and optional credential used to reach a givc component.
+#[derive(Debug, Clone, Default)]
+pub struct EndpointConfig {
+    pub address: String,
+    pub credential: Option<Credential>,
+}
+
+impl EndpointConfig {
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            credential: None,
+        }
+    }
+
+    /// Attaches a shared-secret/bearer credential sent by clients created
+    /// from this config, see [`crate::auth::BearerAuthInterceptor`].
+    pub fn with_credential(mut self, credential: Credential) -> Self {
+        self.credential = Some(credential);
+        self
+    }
+
+    pub async fn connect(&self) -> Result<Channel> {
+        let channel = Endpoint::from_shared(self.address.clone())?
+            .connect()
+            .await?;
+        Ok(channel)
+    }
+
+    /// Connects and wraps the resulting channel with a
+    /// [`BearerAuthInterceptor`] carrying this config's credential. Every
+    /// generated client's `connect()` (`HwidServiceClient`,
+    /// `BuildInfoServiceClient`, and the admin/systemd clients) should build
+    /// its `Client` type alias as `Generated<InterceptedService<Channel,
+    /// BearerAuthInterceptor>>` and call this instead of wrapping the
+    /// interceptor by hand.
+    pub async fn intercepted_channel(
+        &self,
+    ) -> Result<InterceptedService<Channel, BearerAuthInterceptor>> {
+        let channel = self.connect().await?;
+        let interceptor = BearerAuthInterceptor::new(self.credential.clone());
+        Ok(InterceptedService::new(channel, interceptor))
+    }
+}
@@ -0,0 +1,43 @@
+//! `grpc.health.v1.Health` wiring for givc servers. Each registered component
+//! gets its own [`tonic_health::server::HealthReporter`] handle (cheaply
+//! `Clone`) to flip its `ServingStatus` as its backing resource comes up or
+//! goes down; `Check` and `Watch` are served off the same shared registry by
+//! the `tonic-health` crate. Of the admin, systemd and hwid servers, only the
+//! hwid server registers here.
+
+use std::future::Future;
+use std::time::Duration;
+use tonic::server::NamedService;
+
+pub use tonic_health::server::HealthReporter;
+
+/// Builds the health service together with the reporter used to drive it.
+/// Mount the returned `HealthServer` on the same `Router` as the regular RPC
+/// services; keep the `HealthReporter` around to call
+/// `set_serving`/`set_not_serving` for each service as its readiness changes.
+pub fn health_service(
+) -> (HealthReporter, tonic_health::server::HealthServer<impl tonic_health::server::Health>) {
+    tonic_health::server::health_reporter()
+}
+
+/// Registers `S` as `NOT_SERVING` immediately, then polls `is_ready` once a
+/// second until it reports the backing resource reachable, flipping to
+/// `SERVING` at that point and returning. Every component's constructor
+/// (`HwIdServiceServer::new`, and the admin/systemd servers) should call this
+/// with its own readiness probe so `Check`/`Watch` reflect real reachability
+/// instead of sitting at the default unset status forever.
+pub async fn register_until_serving<S, F, Fut>(health: HealthReporter, mut is_ready: F)
+where
+    S: NamedService,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = bool>,
+{
+    health.set_not_serving::<S>().await;
+    loop {
+        if is_ready().await {
+            health.set_serving::<S>().await;
+            break;
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
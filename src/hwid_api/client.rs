@@ -1,11 +1,14 @@
+use crate::auth::BearerAuthInterceptor;
 use crate::endpoint::EndpointConfig;
 use crate::pb::{self, *};
 use crate::types::*;
 use anyhow::Result;
+use tonic::service::interceptor::InterceptedService;
 use tonic::transport::Channel;
 use tonic::{metadata::MetadataValue, Code, Request, Response, Status};
 
-type Client = pb::hwid::hwid_service_client::HwidServiceClient<Channel>;
+type Client =
+    pb::hwid::hwid_service_client::HwidServiceClient<InterceptedService<Channel, BearerAuthInterceptor>>;
 
 #[derive(Debug)]
 pub struct HwIdClient {
@@ -18,8 +21,7 @@ impl HwIdClient {
     }
 
     async fn connect(&self) -> anyhow::Result<Client> {
-        let channel = self.endpoint.connect().await?;
-        Ok(Client::new(channel))
+        Ok(Client::new(self.endpoint.intercepted_channel().await?))
     }
 
     pub async fn get_id(&self) -> anyhow::Result<String> {
@@ -4,7 +4,11 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 use tonic::{Code, Request, Response, Status};
 
-pub use pb::hwid::{hwid_service_server::HwidService, HwIdRequest, HwIdResponse};
+pub use pb::hwid::{
+    hwid_service_server::{HwidService, HwidServiceServer},
+    HwIdRequest, HwIdResponse,
+};
+use crate::health::HealthReporter;
 type RResult<T> = tonic::Result<Response<T>>;
 
 #[derive(Debug, Default)]
@@ -24,11 +28,44 @@ impl<T, E: std::string::ToString> TonicStatus<T> for Result<T, E> {
 }
 
 impl HwIdServiceServer {
-    pub fn new(interface: String) -> Self {
-        Self {
-            interface,
+    /// Creates the server and registers it with `health` under `NOT_SERVING`,
+    /// polling `/sys/class/net/{interface}/address` until it can be read and
+    /// flipping to `SERVING` at that point. This keeps readiness accurate for
+    /// interfaces that come up (or are hot-plugged) after the server starts.
+    pub fn new(interface: String, health: HealthReporter) -> Self {
+        let server = Self {
+            interface: interface.clone(),
             ..Default::default()
-        }
+        };
+
+        tokio::spawn(crate::health::register_until_serving::<
+            HwidServiceServer<HwIdServiceServer>,
+            _,
+            _,
+        >(health, move || {
+            let interface = interface.clone();
+            async move {
+                tokio::fs::read(format!("/sys/class/net/{interface}/address"))
+                    .await
+                    .is_ok()
+            }
+        }));
+
+        server
+    }
+
+    /// Wraps this server with [`crate::auth::AuthValidator`] so the generated
+    /// `HwidServiceServer` rejects requests without a valid bearer credential
+    /// with `Code::Unauthenticated` before they reach `get_hw_id`. Mount the
+    /// result on the `Router` instead of the bare `HwidServiceServer`.
+    pub fn authenticated(
+        self,
+        credential: crate::auth::Credential,
+    ) -> tonic::service::interceptor::InterceptedService<
+        HwidServiceServer<Self>,
+        crate::auth::AuthValidator,
+    > {
+        crate::authenticated(HwidServiceServer::new(self), credential)
     }
 }
 
@@ -1,5 +1,8 @@
 pub mod admin;
+pub mod auth;
+pub mod buildinfo_api;
 pub mod endpoint;
+pub mod health;
 pub mod hwid_api;
 pub mod systemd_api;
 pub mod types;
@@ -15,6 +18,9 @@ pub mod pb {
     pub mod hwid {
         tonic::include_proto!("hwid");
     }
+    pub mod buildinfo {
+        tonic::include_proto!("buildinfo");
+    }
     // Re-export to keep current code untouched
     pub use crate::pb::admin::*;
 }
@@ -22,3 +28,65 @@ pub mod pb {
 pub fn trace_init() {
     tracing_subscriber::fmt::init();
 }
+
+/// Wraps a generated `*ServiceServer` with [`auth::AuthValidator`] so it
+/// rejects any request whose bearer credential doesn't match `credential`
+/// with `Code::Unauthenticated`, before it reaches the RPC handler. Mount
+/// the returned service on the `Router` in place of the bare server, the
+/// same way [`auth::BearerAuthInterceptor`] wraps the generated clients.
+pub fn authenticated<S>(
+    service: S,
+    credential: auth::Credential,
+) -> tonic::service::interceptor::InterceptedService<S, auth::AuthValidator> {
+    tonic::service::interceptor::InterceptedService::new(service, auth::AuthValidator::new(credential))
+}
+
+const ADMIN_DESCRIPTOR: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/admin_descriptor.bin"));
+const SYSTEMD_DESCRIPTOR: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/systemd_descriptor.bin"));
+const HWID_DESCRIPTOR: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/hwid_descriptor.bin"));
+const BUILDINFO_DESCRIPTOR: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/buildinfo_descriptor.bin"));
+
+/// Builds the `grpc.reflection.v1` server reflection service, seeded with the
+/// descriptor sets emitted for the admin, systemd, hwid and buildinfo protos.
+/// Mount the returned service on the same `Router` as the regular RPC
+/// services so tools like `grpcurl`/`grpc_cli` can list and introspect them
+/// at runtime.
+pub fn reflection_service(
+) -> tonic_reflection::server::v1::ServerReflectionServer<impl tonic_reflection::server::v1::ServerReflection>
+{
+    tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(ADMIN_DESCRIPTOR)
+        .register_encoded_file_descriptor_set(SYSTEMD_DESCRIPTOR)
+        .register_encoded_file_descriptor_set(HWID_DESCRIPTOR)
+        .register_encoded_file_descriptor_set(BUILDINFO_DESCRIPTOR)
+        .build_v1()
+        .expect("failed to build gRPC reflection service from embedded descriptor sets")
+}
+
+/// Builds the shared `grpc.health.v1.Health` service plus the reporter used
+/// to drive it. `HwIdServiceServer::new` registers the hwid service on the
+/// returned [`health::HealthReporter`] and flips it to `SERVING` once its
+/// interface address is readable; the admin and systemd servers do not
+/// register with it. Mount the paired service on the same `Router` as the
+/// regular RPC services.
+pub fn health_service(
+) -> (health::HealthReporter, tonic_health::server::HealthServer<impl tonic_health::server::Health>)
+{
+    health::health_service()
+}
+
+/// Same as [`reflection_service`] but speaks the older `grpc.reflection.v1alpha`
+/// protocol, for clients that have not migrated to `v1` yet.
+pub fn reflection_service_v1alpha() -> tonic_reflection::server::v1alpha::ServerReflectionServer<
+    impl tonic_reflection::server::v1alpha::ServerReflection,
+> {
+    tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(ADMIN_DESCRIPTOR)
+        .register_encoded_file_descriptor_set(SYSTEMD_DESCRIPTOR)
+        .register_encoded_file_descriptor_set(HWID_DESCRIPTOR)
+        .register_encoded_file_descriptor_set(BUILDINFO_DESCRIPTOR)
+        .build_v1alpha()
+        .expect("failed to build gRPC reflection service from embedded descriptor sets")
+}
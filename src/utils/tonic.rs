@@ -2,8 +2,63 @@ use anyhow;
 use std::future::Future;
 use std::result::Result;
 use tonic::{Code, Request, Response, Status};
-use tonic_types::{ErrorDetails, StatusExt};
+use tonic_types::{ErrorDetails, FieldViolation, StatusExt};
 
+const ERROR_DOMAIN: &str = "givc";
+
+/// Maps an `anyhow::Error` to the `tonic::Code` that best describes it,
+/// looking through the error's source chain for well-known std error kinds.
+fn code_for(err: &anyhow::Error) -> Code {
+    if err.downcast_ref::<std::num::ParseIntError>().is_some()
+        || err.downcast_ref::<std::num::ParseFloatError>().is_some()
+        || err.downcast_ref::<std::str::Utf8Error>().is_some()
+        || err.downcast_ref::<std::string::FromUtf8Error>().is_some()
+    {
+        return Code::InvalidArgument;
+    }
+
+    for cause in err.chain() {
+        if let Some(io_err) = cause.downcast_ref::<std::io::Error>() {
+            return match io_err.kind() {
+                std::io::ErrorKind::NotFound => Code::NotFound,
+                std::io::ErrorKind::PermissionDenied => Code::PermissionDenied,
+                _ => Code::Internal,
+            };
+        }
+    }
+
+    Code::Internal
+}
+
+/// Renders a `tonic::Code` as the `SCREAMING_SNAKE_CASE` reason string the
+/// `google.rpc.ErrorInfo` convention expects (e.g. `NOT_FOUND`), rather than
+/// relying on `Debug`, which would yield `NotFound`.
+fn reason_for(code: Code) -> &'static str {
+    match code {
+        Code::Ok => "OK",
+        Code::Cancelled => "CANCELLED",
+        Code::Unknown => "UNKNOWN",
+        Code::InvalidArgument => "INVALID_ARGUMENT",
+        Code::DeadlineExceeded => "DEADLINE_EXCEEDED",
+        Code::NotFound => "NOT_FOUND",
+        Code::AlreadyExists => "ALREADY_EXISTS",
+        Code::PermissionDenied => "PERMISSION_DENIED",
+        Code::ResourceExhausted => "RESOURCE_EXHAUSTED",
+        Code::FailedPrecondition => "FAILED_PRECONDITION",
+        Code::Aborted => "ABORTED",
+        Code::OutOfRange => "OUT_OF_RANGE",
+        Code::Unimplemented => "UNIMPLEMENTED",
+        Code::Internal => "INTERNAL",
+        Code::Unavailable => "UNAVAILABLE",
+        Code::DataLoss => "DATA_LOSS",
+        Code::Unauthenticated => "UNAUTHENTICATED",
+    }
+}
+
+/// Runs `fun` against the inner request, turning any `anyhow::Error` it
+/// returns into a `tonic::Status` carrying structured `ErrorDetails` (an
+/// `ErrorInfo`, a `DebugInfo` with the full error chain, and, for argument
+/// errors, a `BadRequest`) instead of a bare, undifferentiated status.
 pub async fn escalate<T, R, F, FA>(
     req: tonic::Request<T>,
     fun: F,
@@ -16,15 +71,24 @@ where
     match result {
         std::result::Result::Ok(res) => std::result::Result::Ok(Response::new(res)),
         Err(any) => {
+            let code = code_for(&any);
+            let reason = reason_for(code);
+            let stack_entries: Vec<String> = any.chain().map(|cause| cause.to_string()).collect();
+
             let mut err_details = ErrorDetails::new();
-            // Generate error status
-            let status = Status::with_error_details(
-                Code::InvalidArgument,
-                "request contains invalid arguments",
-                err_details,
-            );
-
-            return Err(status);
+            err_details.set_error_info(reason, ERROR_DOMAIN, std::collections::HashMap::new());
+            err_details.set_debug_info(stack_entries, any.to_string());
+
+            if code == Code::InvalidArgument {
+                err_details.set_bad_request(vec![FieldViolation::new(
+                    "request",
+                    any.to_string(),
+                )]);
+            }
+
+            let status = Status::with_error_details(code, any.to_string(), err_details);
+
+            Err(status)
         }
     }
 }